@@ -0,0 +1,61 @@
+use quote;
+use syn;
+
+/// Pull the `name` out of a `#[column(name = "...")]` helper attribute on a
+/// single field, if present.
+fn extract_column_name(attrs: &[syn::Attribute]) -> Option<String> {
+    for attr in attrs {
+        if let syn::MetaItem::List(ref ident, ref nested) = attr.value {
+            if ident != "column" {
+                continue;
+            }
+            for item in nested {
+                if let syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(
+                    ref key,
+                    syn::Lit::Str(ref value, _),
+                )) = *item
+                {
+                    if key == "name" {
+                        return Some(value.clone());
+                    }
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn impl_to_column_names(ast: &syn::MacroInput) -> quote::Tokens {
+    let name = &ast.ident;
+    let fields = match ast.body {
+        syn::Body::Struct(syn::VariantData::Struct(ref fields)) => fields,
+        _ => panic!("#[derive(ToColumnNames)] only works on structs with named fields"),
+    };
+
+    let column_names: Vec<quote::Tokens> = fields
+        .iter()
+        .map(|field| {
+            let field_ident = field.ident.as_ref().expect("field must be named");
+            let column_name =
+                extract_column_name(&field.attrs).unwrap_or_else(|| field_ident.to_string());
+            quote! {
+                wumn_dao::ColumnName{
+                    name: #column_name.into(),
+                    table: None,
+                    alias: None,
+                }
+            }
+        })
+        .collect();
+
+    quote! {
+        impl wumn_dao::ToColumnNames for #name {
+
+            fn to_column_names() -> Vec<wumn_dao::ColumnName> {
+                vec![
+                    #(#column_names),*
+                ]
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+extern crate proc_macro;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+
+mod column_derive;
+mod table_derive;
+
+#[proc_macro_derive(ToTableName, attributes(table))]
+pub fn to_table_name(input: TokenStream) -> TokenStream {
+    let source = input.to_string();
+    let ast = syn::parse_macro_input(&source).expect("unable to parse struct for ToTableName");
+    table_derive::impl_to_table_name(&ast).parse().unwrap()
+}
+
+#[proc_macro_derive(ToColumnNames, attributes(column))]
+pub fn to_column_names(input: TokenStream) -> TokenStream {
+    let source = input.to_string();
+    let ast = syn::parse_macro_input(&source).expect("unable to parse struct for ToColumnNames");
+    column_derive::impl_to_column_names(&ast).parse().unwrap()
+}
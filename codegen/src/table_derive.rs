@@ -1,16 +1,69 @@
 use quote;
 use syn;
 
+/// The parts of `#[table(...)]` we care about when generating `ToTableName`.
+struct TableAttr {
+    name: Option<String>,
+    schema: Option<String>,
+    alias: Option<String>,
+}
+
+/// Look for a `#[table(name = "...", schema = "...", alias = "...")]` helper
+/// attribute on the struct and pull out whichever keys were set.
+fn extract_table_attr(attrs: &[syn::Attribute]) -> TableAttr {
+    let mut table_attr = TableAttr {
+        name: None,
+        schema: None,
+        alias: None,
+    };
+    for attr in attrs {
+        if let syn::MetaItem::List(ref ident, ref nested) = attr.value {
+            if ident != "table" {
+                continue;
+            }
+            for item in nested {
+                if let syn::NestedMetaItem::MetaItem(syn::MetaItem::NameValue(
+                    ref key,
+                    syn::Lit::Str(ref value, _),
+                )) = *item
+                {
+                    match key.as_ref() {
+                        "name" => table_attr.name = Some(value.clone()),
+                        "schema" => table_attr.schema = Some(value.clone()),
+                        "alias" => table_attr.alias = Some(value.clone()),
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    table_attr
+}
+
 pub fn impl_to_table_name(ast: &syn::MacroInput) -> quote::Tokens {
     let name = &ast.ident;
+    let table_attr = extract_table_attr(&ast.attrs);
+
+    let table_name = table_attr
+        .name
+        .unwrap_or_else(|| name.to_string().to_lowercase());
+    let schema = match table_attr.schema {
+        Some(schema) => quote! { Some(#schema.into()) },
+        None => quote! { None },
+    };
+    let alias = match table_attr.alias {
+        Some(alias) => quote! { Some(#alias.into()) },
+        None => quote! { None },
+    };
+
     quote! {
         impl wumn_dao::ToTableName for  #name {
 
             fn to_table_name() -> wumn_dao::TableName {
                 wumn_dao::TableName{
-                    name: stringify!(#name).to_lowercase().into(),
-                    schema: None,
-                    alias: None,
+                    name: #table_name.into(),
+                    schema: #schema,
+                    alias: #alias,
                 }
             }
         }
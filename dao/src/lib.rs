@@ -5,7 +5,7 @@ pub use column_name::{ColumnName, ToColumnNames};
 pub use dao::{Dao, FromDao, ToDao};
 pub use error::{ConvertError, DaoError};
 pub use table_name::{TableName, ToTableName};
-pub use value::{ToValue, Value, Array};
+pub use value::{FromValue, ToValue, Value, Array};
 pub use interval::Interval;
 pub use rows::Rows;
 
@@ -0,0 +1,169 @@
+//!
+//! The dynamically typed value carried in and out of a `Dao`/`Rows`.
+//!
+use std::collections::BTreeMap;
+
+pub type Array = Vec<Value>;
+pub type Map = BTreeMap<String, Value>;
+
+/// A single database value, typed loosely enough to cover every backend
+/// `wumn` talks to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Nil,
+    Bool(bool),
+    Bigint(i64),
+    Double(f64),
+    Text(String),
+    Blob(Vec<u8>),
+}
+
+/// Convert a Rust value into the `Value` that goes over the wire to the
+/// database.
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+/// The reverse of [`ToValue`]: reconstruct a Rust value from a `Value`
+/// that came back from the database.
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Self;
+}
+
+macro_rules! impl_to_value {
+    ($ty:ty, $variant:ident) => {
+        impl ToValue for $ty {
+            fn to_value(&self) -> Value {
+                Value::$variant(self.clone().into())
+            }
+        }
+    };
+}
+
+impl_to_value!(bool, Bool);
+impl_to_value!(i32, Bigint);
+impl_to_value!(i64, Bigint);
+impl_to_value!(f32, Double);
+impl_to_value!(f64, Double);
+impl_to_value!(String, Text);
+
+impl<'a> ToValue for &'a str {
+    fn to_value(&self) -> Value {
+        Value::Text(self.to_string())
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Self {
+        match *value {
+            Value::Bool(b) => b,
+            ref v => panic!("unable to convert {:?} to bool", v),
+        }
+    }
+}
+
+impl FromValue for i32 {
+    fn from_value(value: &Value) -> Self {
+        match *value {
+            Value::Bigint(i) => i as i32,
+            ref v => panic!("unable to convert {:?} to i32", v),
+        }
+    }
+}
+
+impl FromValue for i64 {
+    fn from_value(value: &Value) -> Self {
+        match *value {
+            Value::Bigint(i) => i,
+            ref v => panic!("unable to convert {:?} to i64", v),
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Self {
+        match *value {
+            Value::Double(f) => f,
+            ref v => panic!("unable to convert {:?} to f64", v),
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Self {
+        match *value {
+            Value::Text(ref s) => s.clone(),
+            ref v => panic!("unable to convert {:?} to String", v),
+        }
+    }
+}
+
+#[cfg(feature = "with-sqlite")]
+impl rusqlite::types::ToSql for Value {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        use rusqlite::types::{ToSqlOutput, Value as SqlValue};
+        Ok(match *self {
+            Value::Nil => ToSqlOutput::Owned(SqlValue::Null),
+            Value::Bool(b) => ToSqlOutput::Owned(SqlValue::Integer(b as i64)),
+            Value::Bigint(i) => ToSqlOutput::Owned(SqlValue::Integer(i)),
+            Value::Double(f) => ToSqlOutput::Owned(SqlValue::Real(f)),
+            Value::Text(ref s) => ToSqlOutput::Owned(SqlValue::Text(s.clone())),
+            Value::Blob(ref b) => ToSqlOutput::Owned(SqlValue::Blob(b.clone())),
+        })
+    }
+}
+
+/// Derive transparent `ToValue`/`FromValue` for a single-field tuple struct
+/// (a newtype, e.g. `struct ActorId(i32)`) by forwarding to the inner
+/// field's own conversion. Lets domain newtypes round-trip through
+/// `Dao`/`Rows` without manual boilerplate.
+///
+/// ```ignore
+/// struct ActorId(i32);
+/// newtype!(ActorId);
+/// ```
+#[macro_export]
+macro_rules! newtype {
+    ($name:ident) => {
+        impl $crate::value::ToValue for $name {
+            fn to_value(&self) -> $crate::value::Value {
+                $crate::value::ToValue::to_value(&self.0)
+            }
+        }
+
+        impl $crate::value::FromValue for $name {
+            fn from_value(value: &$crate::value::Value) -> Self {
+                $name($crate::value::FromValue::from_value(value))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct ActorId(i32);
+    newtype!(ActorId);
+
+    #[derive(Debug, PartialEq)]
+    struct HashedText(String);
+    newtype!(HashedText);
+
+    #[test]
+    fn newtype_round_trips_through_value() {
+        let id = ActorId(42);
+        let value = id.to_value();
+        assert_eq!(value, Value::Bigint(42));
+        assert_eq!(ActorId::from_value(&value), ActorId(42));
+    }
+
+    #[test]
+    fn newtype_round_trips_a_string_inner_type() {
+        let text = HashedText("s3cr3t".to_string());
+        let value = text.to_value();
+        assert_eq!(value, Value::Text("s3cr3t".to_string()));
+        assert_eq!(HashedText::from_value(&value), HashedText("s3cr3t".to_string()));
+    }
+}
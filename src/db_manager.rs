@@ -4,30 +4,89 @@ use error::{ConnectError};
 use platform::DBPlatform;
 use platform::Platform;
 use dao_manager::DaoManager;
+use std::collections::BTreeMap;
 use std::convert::TryFrom;
+use std::sync::Arc;
+use std::time::Duration;
 
 cfg_if! {if #[cfg(feature = "with-postgres")]{
     use pg::{self, PostgresDB};
 }}
 
+cfg_if! {if #[cfg(feature = "with-sqlite")]{
+    use sq::{self, SqliteDB};
+}}
+
+/// Tunables for the pools `DbManager` creates on first use of a db_url.
+#[derive(Clone, Copy, Debug)]
+pub struct DbManagerConfig {
+    /// Maximum number of connections a single db_url's pool may hold.
+    pub max_pool_size: u32,
+    /// How long to wait for a connection to become available before giving up.
+    pub connection_timeout: Duration,
+}
 
-pub struct DbManager;
+impl Default for DbManagerConfig {
+    fn default() -> Self {
+        DbManagerConfig {
+            max_pool_size: 10,
+            connection_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// The pool backing a single db_url, kept alive for the lifetime of the
+/// `DbManager` so repeated `em`/`dm` calls share connections instead of
+/// opening a fresh pool every time.
+enum Pool {
+    #[cfg(feature = "with-postgres")]
+    Postgres(Arc<pg::Pool>),
+    #[cfg(feature = "with-sqlite")]
+    Sqlite(Arc<sq::Pool>),
+}
+
+pub struct DbManager {
+    config: DbManagerConfig,
+    pools: BTreeMap<String, Pool>,
+}
 
 impl DbManager {
     pub fn new() -> Self {
-        DbManager
+        Self::with_config(DbManagerConfig::default())
     }
 
-    /// ensure that a connection pool for this db_url exist
+    pub fn with_config(config: DbManagerConfig) -> Self {
+        DbManager {
+            config,
+            pools: BTreeMap::new(),
+        }
+    }
+
+    /// Ensure a connection pool for this db_url exists, reusing it if one
+    /// was already created for the same (normalized) db_url, and check out
+    /// a connection from it.
     fn db(&mut self, db_url: &str) -> Result<DBPlatform, DbError> {
         info!("ensure db_url: {}", db_url);
+        let db_url = db_url.trim_end_matches('/');
+        if let Some(pool) = self.pools.get(db_url) {
+            return Self::checkout(pool);
+        }
         let platform: Result<Platform, _> = TryFrom::try_from(db_url);
         match platform {
             Ok(platform) => match platform {
                 #[cfg(feature = "with-postgres")]
                 Platform::Postgres => {
-                    let conn = pg::init_connection(db_url);
-                    Ok(DBPlatform::Postgres(PostgresDB(conn)))
+                    let pool = Arc::new(pg::init_pool(db_url, &self.config));
+                    let db = Self::checkout(&Pool::Postgres(Arc::clone(&pool)));
+                    self.pools.insert(db_url.to_string(), Pool::Postgres(pool));
+                    db
+                },
+                #[cfg(feature = "with-sqlite")]
+                Platform::Sqlite => {
+                    let pool = Arc::new(sq::init_pool(db_url, &self.config));
+                    let db = Self::checkout(&Pool::Sqlite(Arc::clone(&pool)));
+                    self.pools.insert(db_url.to_string(), Pool::Sqlite(pool));
+                    db
                 },
                 Platform::Unsupported(scheme) => {
                     info!("unsupported");
@@ -38,6 +97,21 @@ impl DbManager {
         }
     }
 
+    fn checkout(pool: &Pool) -> Result<DBPlatform, DbError> {
+        match pool {
+            #[cfg(feature = "with-postgres")]
+            Pool::Postgres(pool) => {
+                let conn = pool.get().map_err(|e| DbError::ConnectError(ConnectError::PoolError(e.to_string())))?;
+                Ok(DBPlatform::Postgres(Box::new(PostgresDB(conn))))
+            },
+            #[cfg(feature = "with-sqlite")]
+            Pool::Sqlite(pool) => {
+                let conn = pool.get().map_err(|e| DbError::ConnectError(ConnectError::PoolError(e.to_string())))?;
+                Ok(DBPlatform::Sqlite(Box::new(SqliteDB(conn))))
+            },
+        }
+    }
+
     pub fn em(&mut self, db_url: &str) -> Result<EntityManager, DbError> {
         let db = self.db(db_url)?;
         Ok(EntityManager(db))
@@ -48,4 +122,3 @@ impl DbManager {
         Ok(DaoManager(db))
     }
 }
-
@@ -0,0 +1,63 @@
+use crate::error::DbError;
+use crate::platform::DBPlatform;
+use crate::query::QueryBuilder;
+use wumn_dao::{FromDao, ToColumnNames, ToDao, ToTableName, Value};
+
+/// A checked-out connection to a specific backend, handed out by
+/// `DbManager::em`, through which typed reads and writes happen.
+pub struct EntityManager(pub(crate) DBPlatform);
+
+impl EntityManager {
+    /// Run `sql` with the given positional `params` and map every returned
+    /// row into `T`.
+    pub fn execute_sql_with_return<T>(
+        &self,
+        sql: &str,
+        params: &[&Value],
+    ) -> Result<Vec<T>, DbError>
+    where
+        T: FromDao,
+    {
+        let rows = self.0.execute_sql_with_return(sql, params)?;
+        Ok(rows.iter().map(T::from_dao).collect())
+    }
+
+    /// Start a type-safe, fluent query against `T`'s table, using the table
+    /// and column names its `ToTableName`/`ToColumnNames` derives already
+    /// know, instead of a hand-written SQL string.
+    pub fn select<T>(&self) -> QueryBuilder<T>
+    where
+        T: FromDao + ToTableName + ToColumnNames,
+    {
+        QueryBuilder::new(&self.0)
+    }
+
+    /// Insert each of `entities` and return the rows as inserted, re-read
+    /// back from the database so generated columns (ids, timestamps, ...)
+    /// come along.
+    pub fn insert<T, R>(&self, entities: &[&T]) -> Result<Vec<R>, DbError>
+    where
+        T: ToDao + ToTableName,
+        R: FromDao,
+    {
+        let table_name = T::to_table_name();
+        let mut inserted = Vec::with_capacity(entities.len());
+        for entity in entities {
+            let dao = entity.to_dao();
+            let columns: Vec<String> = dao.iter().map(|(column, _)| column.to_string()).collect();
+            let values: Vec<&Value> = dao.iter().map(|(_, value)| value).collect();
+            let placeholders: Vec<String> = (1..=values.len())
+                .map(|position| self.0.placeholder(position))
+                .collect();
+            let sql = format!(
+                "INSERT INTO {} ({}) VALUES ({}) RETURNING *",
+                table_name.complete_name(),
+                columns.join(", "),
+                placeholders.join(", "),
+            );
+            let rows = self.0.execute_sql_with_return(&sql, &values)?;
+            inserted.extend(rows.iter().map(R::from_dao));
+        }
+        Ok(inserted)
+    }
+}
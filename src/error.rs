@@ -0,0 +1,93 @@
+//!
+//! Error types returned by `wumn`'s public API.
+//!
+use std::fmt;
+
+/// Failed to make sense of a db_url.
+#[derive(Debug)]
+pub enum ParseError {
+    DbUrlParseError(url::ParseError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseError::DbUrlParseError(ref e) => write!(f, "unable to parse db_url: {}", e),
+        }
+    }
+}
+
+/// Failed to establish or check out a connection.
+#[derive(Debug)]
+pub enum ConnectError {
+    UnsupportedDb(String),
+    ParseError(ParseError),
+    /// Checking out a connection from an existing pool failed (e.g. no
+    /// connection became available within the connection timeout).
+    PoolError(String),
+}
+
+impl fmt::Display for ConnectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ConnectError::UnsupportedDb(ref scheme) => write!(f, "unsupported db scheme: {}", scheme),
+            ConnectError::ParseError(ref e) => write!(f, "{}", e),
+            ConnectError::PoolError(ref e) => write!(f, "unable to check out a connection: {}", e),
+        }
+    }
+}
+
+/// Failed to convert between a `Value` and the Rust type a `FromDao`/`ToDao`
+/// struct expects.
+#[derive(Debug)]
+pub enum DataError {
+    ConvertError(String),
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DataError::ConvertError(ref e) => write!(f, "unable to convert value: {}", e),
+        }
+    }
+}
+
+/// A backend-specific failure (a bad query, a driver error, ...).
+#[derive(Debug)]
+pub enum PlatformError {
+    SqlError(String),
+}
+
+impl fmt::Display for PlatformError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PlatformError::SqlError(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// The top-level error type every `wumn` public function returns.
+#[derive(Debug)]
+pub enum DbError {
+    ConnectError(ConnectError),
+    DataError(DataError),
+    /// A raw driver/SQL failure, carrying the underlying error's message.
+    PlatformError(String),
+    /// Migrations could not be read from disk, or a migration to roll back
+    /// has no down script. SQL failures while applying or reverting a
+    /// migration are reported as `PlatformError`.
+    MigrationError(String),
+}
+
+impl fmt::Display for DbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DbError::ConnectError(ref e) => write!(f, "{}", e),
+            DbError::DataError(ref e) => write!(f, "{}", e),
+            DbError::PlatformError(ref e) => write!(f, "{}", e),
+            DbError::MigrationError(ref e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DbError {}
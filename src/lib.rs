@@ -91,13 +91,19 @@ extern crate postgres_shared;
 mod pg;
 }}
 
+cfg_if! {if #[cfg(feature = "with-sqlite")]{
+mod sq;
+}}
+
 pub mod common;
 pub mod column;
 mod dao_manager;
 mod db_manager;
 mod database;
 mod entity;
+pub mod migration;
 mod platform;
+pub mod query;
 mod users;
 pub mod error;
 pub mod table;
@@ -106,12 +112,14 @@ pub mod util;
 
 pub use column::Column;
 pub use dao_manager::DaoManager;
-pub use db_manager::DbManager;
+pub use db_manager::{DbManager, DbManagerConfig};
 pub use database::{
     Database,
     DatabaseName,
 };
 pub use entity::EntityManager;
+pub use migration::{Migration, MigrationStatus, Migrator};
+pub use query::{col, Condition, QueryBuilder};
 pub use error::{
     DataError,
     DbError,
@@ -131,12 +139,14 @@ pub use codegen::{
 pub use wumn_dao::{
     ColumnName,
     Dao,
+    FromValue,
     Rows,
     TableName,
     ToValue,
     Value,
     Array,
 };
+pub use wumn_dao::newtype;
 
 /// Wrap the wumn_dao exports to avoid name conflict with the wumn_codegen
 pub mod dao {
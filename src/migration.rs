@@ -0,0 +1,264 @@
+//!
+//! Embedded, versioned SQL migrations, applied against whichever
+//! `DBPlatform` the caller is connected to, in the spirit of migra.
+//!
+use crate::error::DbError;
+use crate::platform::DBPlatform;
+use std::fs;
+use std::path::Path;
+
+/// A single versioned migration: `up` applies it, the optional `down`
+/// reverses it.
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub version: i64,
+    pub name: String,
+    pub up: String,
+    pub down: Option<String>,
+}
+
+/// What's been applied vs. what's still waiting, as reported by
+/// [`Migrator::status`].
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub applied: Vec<Migration>,
+    pub pending: Vec<Migration>,
+}
+
+/// Runs a set of [`Migration`]s against a `DBPlatform`, tracking which
+/// versions have already been applied in a `_migrations` bookkeeping table.
+pub struct Migrator<'a> {
+    db: &'a DBPlatform,
+    migrations: Vec<Migration>,
+}
+
+impl<'a> Migrator<'a> {
+    /// Start a migrator with no migrations loaded yet; add some with
+    /// [`Migrator::with_migrations`] or [`Migrator::from_dir`].
+    pub fn new(db: &'a DBPlatform) -> Self {
+        Migrator {
+            db,
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Load migrations from an in-memory list, sorted by version.
+    pub fn with_migrations(mut self, mut migrations: Vec<Migration>) -> Self {
+        migrations.sort_by_key(|m| m.version);
+        self.migrations = migrations;
+        self
+    }
+
+    /// Load migrations from a directory of `NNNN_name.up.sql` /
+    /// `NNNN_name.down.sql` file pairs.
+    pub fn from_dir(db: &'a DBPlatform, dir: &Path) -> Result<Self, DbError> {
+        let mut by_version: Vec<(i64, String, std::path::PathBuf)> = Vec::new();
+        for entry in fs::read_dir(dir).map_err(|e| DbError::MigrationError(e.to_string()))? {
+            let entry = entry.map_err(|e| DbError::MigrationError(e.to_string()))?;
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+            if let Some(stripped) = file_name.strip_suffix(".up.sql") {
+                if let Some((version, name)) = split_version_name(stripped) {
+                    by_version.push((version, name, entry.path()));
+                }
+            }
+        }
+        by_version.sort_by_key(|(version, _, _)| *version);
+
+        let mut migrations = Vec::new();
+        for (version, name, up_path) in by_version {
+            let down_path = up_path.with_extension("").with_extension("down.sql");
+            let up =
+                fs::read_to_string(&up_path).map_err(|e| DbError::MigrationError(e.to_string()))?;
+            let down = fs::read_to_string(&down_path).ok();
+            migrations.push(Migration {
+                version,
+                name,
+                up,
+                down,
+            });
+        }
+        Ok(Migrator { db, migrations })
+    }
+
+    /// Create the `_migrations` bookkeeping table if it doesn't exist yet.
+    fn ensure_migrations_table(&self) -> Result<(), DbError> {
+        self.db.execute_sql_with_return(
+            "CREATE TABLE IF NOT EXISTS _migrations (\
+                version BIGINT PRIMARY KEY, \
+                name TEXT NOT NULL, \
+                applied_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP\
+            )",
+            &[],
+        )?;
+        Ok(())
+    }
+
+    fn applied_versions(&self) -> Result<Vec<i64>, DbError> {
+        self.ensure_migrations_table()?;
+        let rows = self
+            .db
+            .execute_sql_with_return("SELECT version FROM _migrations ORDER BY version", &[])?;
+        Ok(rows
+            .iter()
+            .filter_map(|row| row.get("version").map(wumn_dao::FromValue::from_value))
+            .collect())
+    }
+
+    fn pending(&self) -> Result<Vec<&Migration>, DbError> {
+        let applied = self.applied_versions()?;
+        Ok(self
+            .migrations
+            .iter()
+            .filter(|m| !applied.contains(&m.version))
+            .collect())
+    }
+
+    /// Report which migrations have been applied and which are pending.
+    pub fn status(&self) -> Result<MigrationStatus, DbError> {
+        let applied_versions = self.applied_versions()?;
+        let applied = self
+            .migrations
+            .iter()
+            .filter(|m| applied_versions.contains(&m.version))
+            .cloned()
+            .collect();
+        let pending = self.pending()?.into_iter().cloned().collect();
+        Ok(MigrationStatus { applied, pending })
+    }
+
+    /// Apply every pending migration, in version order, each inside its own
+    /// transaction that's rolled back on failure.
+    pub fn migrate_up(&self) -> Result<usize, DbError> {
+        let pending: Vec<Migration> = self.pending()?.into_iter().cloned().collect();
+        for migration in &pending {
+            self.apply_in_transaction(&migration.up, |db| {
+                use wumn_dao::ToValue;
+                db.execute_sql_with_return(
+                    "INSERT INTO _migrations (version, name) VALUES ($1, $2)",
+                    &[
+                        &migration.version.to_value(),
+                        &migration.name.to_value(),
+                    ],
+                )?;
+                Ok(())
+            })?;
+        }
+        Ok(pending.len())
+    }
+
+    /// Roll back the `steps` most recently applied migrations, in reverse
+    /// version order.
+    pub fn migrate_down(&self, steps: usize) -> Result<usize, DbError> {
+        let applied_versions = self.applied_versions()?;
+        let mut to_revert: Vec<&Migration> = self
+            .migrations
+            .iter()
+            .filter(|m| applied_versions.contains(&m.version))
+            .collect();
+        to_revert.sort_by_key(|m| std::cmp::Reverse(m.version));
+        to_revert.truncate(steps);
+
+        for migration in &to_revert {
+            let down = migration.down.as_ref().ok_or_else(|| {
+                DbError::MigrationError(format!(
+                    "migration {} ({}) has no down script",
+                    migration.version, migration.name
+                ))
+            })?;
+            self.apply_in_transaction(down, |db| {
+                use wumn_dao::ToValue;
+                db.execute_sql_with_return(
+                    "DELETE FROM _migrations WHERE version = $1",
+                    &[&migration.version.to_value()],
+                )?;
+                Ok(())
+            })?;
+        }
+        Ok(to_revert.len())
+    }
+
+    /// Run `sql` (split into statements) plus `bookkeeping` inside a single
+    /// transaction, rolling back the whole thing on any error.
+    fn apply_in_transaction(
+        &self,
+        sql: &str,
+        bookkeeping: impl FnOnce(&DBPlatform) -> Result<(), DbError>,
+    ) -> Result<(), DbError> {
+        self.db.execute_sql_with_return("BEGIN", &[])?;
+        let result = (|| {
+            for statement in split_statements(sql) {
+                self.db.execute_sql_with_return(&statement, &[])?;
+            }
+            bookkeeping(self.db)
+        })();
+        match result {
+            Ok(()) => {
+                self.db.execute_sql_with_return("COMMIT", &[])?;
+                Ok(())
+            }
+            Err(e) => {
+                self.db.execute_sql_with_return("ROLLBACK", &[])?;
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Strip `--` line comments and split on `;`, dropping empty statements.
+fn split_statements(sql: &str) -> Vec<String> {
+    sql.lines()
+        .map(|line| match line.find("--") {
+            Some(idx) => &line[..idx],
+            None => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .split(';')
+        .map(|stmt| stmt.trim().to_string())
+        .filter(|stmt| !stmt.is_empty())
+        .collect()
+}
+
+/// Split a `NNNN_name` stem into its numeric version and name parts.
+fn split_version_name(stem: &str) -> Option<(i64, String)> {
+    let mut parts = stem.splitn(2, '_');
+    let version = parts.next()?.parse().ok()?;
+    let name = parts.next()?.to_string();
+    Some((version, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_line_comments_and_splits_on_semicolon() {
+        let sql = "CREATE TABLE actor (id INT); -- seed data\nINSERT INTO actor (id) VALUES (1);";
+        assert_eq!(
+            split_statements(sql),
+            vec![
+                "CREATE TABLE actor (id INT)".to_string(),
+                "INSERT INTO actor (id) VALUES (1)".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_empty_statements() {
+        assert_eq!(split_statements(";  ;\n-- just a comment\n;"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn parses_version_and_name_from_stem() {
+        assert_eq!(
+            split_version_name("0001_create_actor"),
+            Some((1, "create_actor".to_string()))
+        );
+    }
+
+    #[test]
+    fn rejects_stem_with_non_numeric_version() {
+        assert_eq!(split_version_name("create_actor"), None);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::database::Database;
+use crate::db_manager::DbManagerConfig;
+use crate::error::DbError;
+use postgres::TlsMode;
+use r2d2_postgres::PostgresConnectionManager;
+use wumn_dao::{Rows, Value};
+
+/// Connection pool type backing a single postgres db_url.
+pub type Pool = r2d2::Pool<PostgresConnectionManager>;
+
+/// Thin wrapper around a pooled postgres connection.
+pub struct PostgresDB(pub r2d2::PooledConnection<PostgresConnectionManager>);
+
+/// Build a connection pool for the given postgres db_url.
+pub fn init_pool(db_url: &str, config: &DbManagerConfig) -> Pool {
+    let manager = PostgresConnectionManager::new(db_url, TlsMode::None)
+        .expect("unable to build postgres connection manager");
+    r2d2::Pool::builder()
+        .max_size(config.max_pool_size)
+        .connection_timeout(config.connection_timeout)
+        .build(manager)
+        .expect("unable to create postgres connection pool")
+}
+
+impl Database for PostgresDB {
+    fn execute_sql_with_return(&self, sql: &str, params: &[&Value]) -> Result<Rows, DbError> {
+        let stmt = self
+            .0
+            .prepare(sql)
+            .map_err(|e| DbError::PlatformError(e.to_string()))?;
+        let column_names: Vec<String> = stmt
+            .columns()
+            .iter()
+            .map(|c| c.name().to_string())
+            .collect();
+        let mut rows = Rows::new(column_names);
+        let query_rows = stmt
+            .query(params)
+            .map_err(|e| DbError::PlatformError(e.to_string()))?;
+        for row in query_rows.iter() {
+            let record: Vec<Value> = (0..row.len()).map(|i| row.get(i)).collect();
+            rows.push(record);
+        }
+        Ok(rows)
+    }
+}
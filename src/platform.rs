@@ -14,10 +14,29 @@ cfg_if! {if #[cfg(feature = "with-postgres")]{
     use crate::pg::PostgresDB;
 }}
 
+cfg_if! {if #[cfg(feature = "with-sqlite")]{
+    use crate::sq::SqliteDB;
+}}
+
 pub enum DBPlatform {
     #[cfg(feature = "with-postgres")]
     //Postgres(PostgresDB),
     Postgres(Box<PostgresDB>),
+    #[cfg(feature = "with-sqlite")]
+    Sqlite(Box<SqliteDB>),
+}
+
+impl DBPlatform {
+    /// The positional placeholder syntax this backend expects for bound
+    /// parameters, e.g. `$1` for Postgres vs. `?` for sqlite.
+    pub(crate) fn placeholder(&self, position: usize) -> String {
+        match *self {
+            #[cfg(feature = "with-postgres")]
+            DBPlatform::Postgres(_) => format!("${}", position),
+            #[cfg(feature = "with-sqlite")]
+            DBPlatform::Sqlite(_) => "?".to_string(),
+        }
+    }
 }
 
 impl Deref for DBPlatform {
@@ -27,6 +46,8 @@ impl Deref for DBPlatform {
         match *self {
             #[cfg(feature = "with-postgres")]
             DBPlatform::Postgres(ref pg) => pg.deref(),
+            #[cfg(feature = "with-sqlite")]
+            DBPlatform::Sqlite(ref sq) => sq.deref(),
         }
     }
 }
@@ -34,6 +55,8 @@ impl Deref for DBPlatform {
 pub(crate) enum Platform {
     #[cfg(feature = "with-postgres")]
     Postgres,
+    #[cfg(feature = "with-sqlite")]
+    Sqlite,
     Unsupported(String),
 }
 
@@ -48,10 +71,46 @@ impl<'a> TryFrom<&'a str> for Platform {
                 match scheme {
                     #[cfg(feature = "with-postgres")]
                     "postgres" => Ok(Platform::Postgres),
+                    #[cfg(feature = "with-sqlite")]
+                    "sqlite" | "sqlite3" => Ok(Platform::Sqlite),
                     _ => Ok(Platform::Unsupported(scheme.to_string())),
                 }
             }
-            Err(e) => Err(ParseError::DbUrlParseError(e)),
+            // `sqlite://:memory:` does not parse as a regular url since the
+            // authority portion (`:memory:`) is not valid, so match the
+            // in-memory urls literally before giving up.
+            Err(e) => {
+                #[cfg(feature = "with-sqlite")]
+                {
+                    if s == "sqlite://:memory:" || s == "sqlite3://:memory:" {
+                        return Ok(Platform::Sqlite);
+                    }
+                }
+                Err(ParseError::DbUrlParseError(e))
+            }
         }
     }
 }
+
+#[cfg(all(test, feature = "with-sqlite"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognizes_sqlite_scheme() {
+        let platform = Platform::try_from("sqlite://test.db").unwrap();
+        assert!(matches!(platform, Platform::Sqlite));
+    }
+
+    #[test]
+    fn recognizes_sqlite3_scheme() {
+        let platform = Platform::try_from("sqlite3://test.db").unwrap();
+        assert!(matches!(platform, Platform::Sqlite));
+    }
+
+    #[test]
+    fn recognizes_sqlite_in_memory_url() {
+        let platform = Platform::try_from("sqlite://:memory:").unwrap();
+        assert!(matches!(platform, Platform::Sqlite));
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! A small, fluent, type-safe query builder over `EntityManager`, so common
+//! reads don't need hand-written SQL strings.
+//!
+use crate::error::DbError;
+use crate::platform::DBPlatform;
+use std::marker::PhantomData;
+use wumn_dao::{FromDao, ToColumnNames, ToTableName, ToValue, Value};
+
+/// A bare column reference, the entry point for building a [`Condition`].
+///
+/// ```ignore
+/// col("first_name").eq("TOM")
+/// ```
+pub fn col(name: &str) -> Column {
+    Column {
+        name: name.to_string(),
+    }
+}
+
+pub struct Column {
+    name: String,
+}
+
+impl Column {
+    pub fn eq<V: ToValue>(self, value: V) -> Condition {
+        Condition::new(self.name, "=", value.to_value())
+    }
+
+    pub fn ne<V: ToValue>(self, value: V) -> Condition {
+        Condition::new(self.name, "<>", value.to_value())
+    }
+
+    pub fn gt<V: ToValue>(self, value: V) -> Condition {
+        Condition::new(self.name, ">", value.to_value())
+    }
+
+    pub fn lt<V: ToValue>(self, value: V) -> Condition {
+        Condition::new(self.name, "<", value.to_value())
+    }
+
+    pub fn like<V: ToValue>(self, value: V) -> Condition {
+        Condition::new(self.name, "LIKE", value.to_value())
+    }
+}
+
+pub struct Condition {
+    column: String,
+    op: &'static str,
+    value: Value,
+}
+
+impl Condition {
+    fn new(column: String, op: &'static str, value: Value) -> Self {
+        Condition { column, op, value }
+    }
+}
+
+enum Order {
+    Asc(String),
+    Desc(String),
+}
+
+/// Fluent builder for `SELECT` statements over a `FromDao`/`ToTableName`/
+/// `ToColumnNames` type. Generates backend-appropriate parameterized SQL
+/// and binds values through the existing `Value`/`ToValue` machinery.
+pub struct QueryBuilder<'a, T> {
+    db: &'a DBPlatform,
+    filters: Vec<Condition>,
+    order_by: Vec<Order>,
+    limit: Option<i64>,
+    offset: Option<i64>,
+    marker: PhantomData<T>,
+}
+
+impl<'a, T> QueryBuilder<'a, T>
+where
+    T: FromDao + ToTableName + ToColumnNames,
+{
+    pub(crate) fn new(db: &'a DBPlatform) -> Self {
+        QueryBuilder {
+            db,
+            filters: Vec::new(),
+            order_by: Vec::new(),
+            limit: None,
+            offset: None,
+            marker: PhantomData,
+        }
+    }
+
+    pub fn filter(mut self, condition: Condition) -> Self {
+        self.filters.push(condition);
+        self
+    }
+
+    pub fn order_by_asc(mut self, column: &str) -> Self {
+        self.order_by.push(Order::Asc(column.to_string()));
+        self
+    }
+
+    pub fn order_by_desc(mut self, column: &str) -> Self {
+        self.order_by.push(Order::Desc(column.to_string()));
+        self
+    }
+
+    pub fn limit(mut self, limit: i64) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn offset(mut self, offset: i64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Build the SQL and bound values, then run it and map every row back
+    /// into `T`.
+    pub fn fetch(self) -> Result<Vec<T>, DbError> {
+        let table_name = T::to_table_name();
+        let columns = T::to_column_names();
+        let column_list = columns
+            .iter()
+            .map(|c| c.name.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut sql = format!("SELECT {} FROM {}", column_list, table_name.complete_name());
+        let mut position = 1;
+        if !self.filters.is_empty() {
+            let clauses: Vec<String> = self
+                .filters
+                .iter()
+                .map(|condition| {
+                    let placeholder = self.db.placeholder(position);
+                    position += 1;
+                    format!("{} {} {}", condition.column, condition.op, placeholder)
+                })
+                .collect();
+            sql.push_str(" WHERE ");
+            sql.push_str(&clauses.join(" AND "));
+        }
+        if !self.order_by.is_empty() {
+            let clauses: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|order| match order {
+                    Order::Asc(column) => format!("{} ASC", column),
+                    Order::Desc(column) => format!("{} DESC", column),
+                })
+                .collect();
+            sql.push_str(" ORDER BY ");
+            sql.push_str(&clauses.join(", "));
+        }
+        if let Some(limit) = self.limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if let Some(offset) = self.offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        let values: Vec<&Value> = self.filters.iter().map(|condition| &condition.value).collect();
+        let rows = self.db.execute_sql_with_return(&sql, &values)?;
+        Ok(rows.iter().map(T::from_dao).collect())
+    }
+}
@@ -0,0 +1,69 @@
+use crate::database::Database;
+use crate::db_manager::DbManagerConfig;
+use crate::error::DbError;
+use r2d2::PooledConnection;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::types::ValueRef;
+use wumn_dao::{Array, Rows, Value};
+
+/// Connection pool type backing a single sqlite db_url.
+pub type Pool = r2d2::Pool<SqliteConnectionManager>;
+
+/// Thin wrapper around a pooled sqlite connection, analogous to `pg::PostgresDB`.
+pub struct SqliteDB(pub PooledConnection<SqliteConnectionManager>);
+
+/// Build a connection pool for the given sqlite db_url.
+///
+/// Accepts both file paths (`sqlite://path/to/file.db`) and the special
+/// `sqlite://:memory:` url for an in-memory database.
+pub fn init_pool(db_url: &str, config: &DbManagerConfig) -> Pool {
+    let path = db_url
+        .trim_start_matches("sqlite://")
+        .trim_start_matches("sqlite3://");
+    let manager = if path.is_empty() || path == ":memory:" {
+        SqliteConnectionManager::memory()
+    } else {
+        SqliteConnectionManager::file(path)
+    };
+    r2d2::Pool::builder()
+        .max_size(config.max_pool_size)
+        .connection_timeout(config.connection_timeout)
+        .build(manager)
+        .expect("unable to create sqlite connection pool")
+}
+
+fn sqlite_value_to_dao(value: ValueRef) -> Value {
+    match value {
+        ValueRef::Null => Value::Nil,
+        ValueRef::Integer(i) => Value::Bigint(i),
+        ValueRef::Real(f) => Value::Double(f),
+        ValueRef::Text(s) => Value::Text(String::from_utf8_lossy(s).to_string()),
+        ValueRef::Blob(b) => Value::Blob(b.to_vec()),
+    }
+}
+
+impl Database for SqliteDB {
+    fn execute_sql_with_return(&self, sql: &str, params: &[&Value]) -> Result<Rows, DbError> {
+        let mut stmt = self
+            .0
+            .prepare(sql)
+            .map_err(|e| DbError::PlatformError(e.to_string()))?;
+        let column_names: Vec<String> =
+            stmt.column_names().iter().map(|c| c.to_string()).collect();
+        let mut rows = Rows::new(column_names);
+        let bound: Array = params.iter().map(|v| (*v).clone()).collect();
+        let mut result_rows = stmt
+            .query(rusqlite::params_from_iter(bound.iter()))
+            .map_err(|e| DbError::PlatformError(e.to_string()))?;
+        while let Some(row) = result_rows
+            .next()
+            .map_err(|e| DbError::PlatformError(e.to_string()))?
+        {
+            let record: Vec<Value> = (0..row.as_ref().column_count())
+                .map(|i| sqlite_value_to_dao(row.get_ref_unwrap(i)))
+                .collect();
+            rows.push(record);
+        }
+        Ok(rows)
+    }
+}